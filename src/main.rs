@@ -1,41 +1,196 @@
 #![allow(unused_labels)]
 
 use std::{
+    collections::HashMap,
     fmt::Display,
+    fs,
     io::{stdin, stdout, Write},
     str::FromStr,
     thread::sleep,
     time::Duration,
 };
 
-use rand::prelude::*;
+use rand::{prelude::*, rngs::StdRng};
 use rodio::{
     source::{SineWave, Source},
     {OutputStream, Sink},
 };
 
-fn note_freq(note: i32) -> f32 {
-    440.0 * 2_f32.powf((note - 9) as f32 / 12.0)
+/// Octave that lines up with `Note(0)`, i.e. the octave printed when no
+/// octave digit is given while parsing.
+const REFERENCE_OCTAVE: i32 = 4;
+
+const NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// A note as a semitone offset from `C` in [`REFERENCE_OCTAVE`].
+///
+/// Displays and parses in scientific pitch notation (`A4`, `Db3`, `F#`),
+/// but still accepts a bare integer offset for compatibility with the
+/// old interface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Note(i32);
+
+impl std::ops::Add<i32> for Note {
+    type Output = Note;
+
+    fn add(self, rhs: i32) -> Note {
+        Note(self.0 + rhs)
+    }
+}
+
+impl Display for Note {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pitch_class = self.0.rem_euclid(12) as usize;
+        let octave = REFERENCE_OCTAVE + self.0.div_euclid(12);
+        write!(f, "{}{octave}", NOTE_NAMES[pitch_class])
+    }
+}
+
+#[derive(Debug)]
+struct NoteParseError(String);
+
+impl Display for NoteParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Note {
+    type Err = NoteParseError;
+
+    /// Parses scientific pitch notation: a letter `A`-`G`, an optional
+    /// accidental (`#`, `x` for double sharp, `b`, `bb` for double flat),
+    /// and an optional signed octave number. A bare integer is still
+    /// accepted as a raw semitone offset.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(raw) = s.parse::<i32>() {
+            return Ok(Note(raw));
+        }
+
+        let mut chars = s.chars();
+        let letter = chars.next().ok_or_else(|| {
+            NoteParseError("expected a note name like \"F#4\" or an integer".to_string())
+        })?;
+
+        let pitch_class = match letter.to_ascii_uppercase() {
+            'C' => 0,
+            'D' => 2,
+            'E' => 4,
+            'F' => 5,
+            'G' => 7,
+            'A' => 9,
+            'B' => 11,
+            other => {
+                return Err(NoteParseError(format!(
+                    "expected a note letter A-G, got '{other}'"
+                )))
+            }
+        };
+
+        let rest = chars.as_str();
+        let (accidental, rest) = if let Some(rest) = rest.strip_prefix("bb") {
+            (-2, rest)
+        } else if let Some(rest) = rest.strip_prefix('x') {
+            (2, rest)
+        } else if let Some(rest) = rest.strip_prefix('#') {
+            (1, rest)
+        } else if let Some(rest) = rest.strip_prefix('b') {
+            (-1, rest)
+        } else {
+            (0, rest)
+        };
+
+        let octave = if rest.is_empty() {
+            REFERENCE_OCTAVE
+        } else {
+            rest.parse::<i32>().map_err(|_| {
+                NoteParseError(format!(
+                    "expected a signed octave number after the accidental, got \"{rest}\""
+                ))
+            })?
+        };
+
+        Ok(Note(pitch_class + accidental + 12 * (octave - REFERENCE_OCTAVE)))
+    }
+}
+
+fn note_freq(note: Note) -> f32 {
+    440.0 * 2_f32.powf((note.0 - 9) as f32 / 12.0)
 }
 
-fn append_note(sink: &Sink, note: i32, duration: Duration) {
+fn append_note(sink: &Sink, note: Note, duration: Duration) {
     let mut wave = SineWave::new(note_freq(note)).take_duration(duration);
     wave.set_filter_fadeout();
     sink.append(wave.amplify(0.5));
 }
 
-fn from_scale(bits: u16) -> Vec<i32> {
+fn from_scale(bits: u16) -> Vec<Note> {
     let mut mask = 1 << 11;
     let mut v = vec![];
     for i in 0..12 {
         if bits & mask != 0 {
-            v.push(i)
+            v.push(Note(i))
         }
         mask >>= 1;
     }
     v
 }
 
+/// A 12-bit mask of scale degrees, MSB-first starting at the root,
+/// resolved to the bitmask that [`from_scale`] expects.
+#[derive(Debug, Clone, Copy)]
+struct ScaleMask(u16);
+
+impl ScaleMask {
+    const MAJOR: ScaleMask = ScaleMask(0b_1010_1101_0101);
+    const NATURAL_MINOR: ScaleMask = ScaleMask(0b_1011_0101_1010);
+    const HARMONIC_MINOR: ScaleMask = ScaleMask(0b_1011_0101_1001);
+    const MAJOR_PENTATONIC: ScaleMask = ScaleMask(0b_1010_1001_0100);
+    const BLUES: ScaleMask = ScaleMask(0b_1001_0111_0010);
+    const CHROMATIC: ScaleMask = ScaleMask(0b_1111_1111_1111);
+}
+
+#[derive(Debug)]
+struct ScaleParseError(String);
+
+impl Display for ScaleParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for ScaleMask {
+    type Err = ScaleParseError;
+
+    /// Parses a scale name (`major`, `natural_minor`, `harmonic_minor`,
+    /// `major_pentatonic`, `blues`, `chromatic`) or a custom 12-bit
+    /// pattern string such as `101011010101`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.to_ascii_lowercase().as_str() {
+            "major" => return Ok(ScaleMask::MAJOR),
+            "natural_minor" => return Ok(ScaleMask::NATURAL_MINOR),
+            "harmonic_minor" => return Ok(ScaleMask::HARMONIC_MINOR),
+            "major_pentatonic" => return Ok(ScaleMask::MAJOR_PENTATONIC),
+            "blues" => return Ok(ScaleMask::BLUES),
+            "chromatic" => return Ok(ScaleMask::CHROMATIC),
+            _ => {}
+        }
+
+        if s.len() == 12 && s.bytes().all(|b| b == b'0' || b == b'1') {
+            return Ok(ScaleMask(u16::from_str_radix(s, 2).unwrap()));
+        }
+
+        Err(ScaleParseError(format!(
+            "expected 12 bits or a scale name (major, natural_minor, harmonic_minor, \
+             major_pentatonic, blues, chromatic), got \"{s}\""
+        )))
+    }
+}
+
 fn input_line(prompt: &str) -> String {
     print!("{prompt}");
     stdout().flush().unwrap();
@@ -45,7 +200,10 @@ fn input_line(prompt: &str) -> String {
     buf
 }
 
-fn input_try<T: FromStr>(msg: &str, prompt: &str, cancel: &str) -> Option<T> {
+fn input_try<T: FromStr>(msg: &str, prompt: &str, cancel: &str) -> Option<T>
+where
+    T::Err: Display,
+{
     if !msg.is_empty() {
         println!("{msg}");
     }
@@ -57,17 +215,17 @@ fn input_try<T: FromStr>(msg: &str, prompt: &str, cancel: &str) -> Option<T> {
         }
         match input.parse() {
             Ok(out) => return Some(out),
-            Err(_) => println!("[Bad input. Try again.]"),
+            Err(e) => println!("[Bad input: {e}. Try again.]"),
         }
     }
 }
 
-fn play(sink: &Sink, note: i32, duration: Duration) {
+fn play(sink: &Sink, note: Note, duration: Duration) {
     append_note(sink, note, duration);
     sink.sleep_until_end();
 }
 
-fn play_scale(sink: &Sink, notes: &[i32], note_duration: Duration) {
+fn play_scale(sink: &Sink, notes: &[Note], note_duration: Duration) {
     for note in notes {
         print!("{note} ");
         stdout().flush().unwrap();
@@ -76,6 +234,118 @@ fn play_scale(sink: &Sink, notes: &[i32], note_duration: Duration) {
     println!();
 }
 
+/// The distance between two notes, 0 (unison) through 12 (octave).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Interval {
+    Unison,
+    MinorSecond,
+    MajorSecond,
+    MinorThird,
+    MajorThird,
+    PerfectFourth,
+    Tritone,
+    PerfectFifth,
+    MinorSixth,
+    MajorSixth,
+    MinorSeventh,
+    MajorSeventh,
+    Octave,
+}
+
+impl Interval {
+    const ALL: [Interval; 13] = [
+        Interval::Unison,
+        Interval::MinorSecond,
+        Interval::MajorSecond,
+        Interval::MinorThird,
+        Interval::MajorThird,
+        Interval::PerfectFourth,
+        Interval::Tritone,
+        Interval::PerfectFifth,
+        Interval::MinorSixth,
+        Interval::MajorSixth,
+        Interval::MinorSeventh,
+        Interval::MajorSeventh,
+        Interval::Octave,
+    ];
+
+    fn semitones(self) -> i32 {
+        self as i32
+    }
+
+    fn from_semitones(n: i32) -> Option<Interval> {
+        Self::ALL.get(n as usize).copied()
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Interval::Unison => "unison",
+            Interval::MinorSecond => "minor second",
+            Interval::MajorSecond => "major second",
+            Interval::MinorThird => "minor third",
+            Interval::MajorThird => "major third",
+            Interval::PerfectFourth => "perfect fourth",
+            Interval::Tritone => "tritone",
+            Interval::PerfectFifth => "perfect fifth",
+            Interval::MinorSixth => "minor sixth",
+            Interval::MajorSixth => "major sixth",
+            Interval::MinorSeventh => "minor seventh",
+            Interval::MajorSeventh => "major seventh",
+            Interval::Octave => "octave",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug)]
+struct IntervalParseError(String);
+
+impl Display for IntervalParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Interval {
+    type Err = IntervalParseError;
+
+    /// Parses an interval name (`"minor third"`, or a short code like
+    /// `"m3"`/`"p5"`) or a raw semitone count 0-12.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(n) = s.parse::<i32>() {
+            return Interval::from_semitones(n)
+                .ok_or_else(|| IntervalParseError(format!("expected 0-12 semitones, got {n}")));
+        }
+
+        let normalized = s.to_ascii_lowercase().replace(['-', '_'], " ");
+        let interval = match normalized.as_str() {
+            "unison" | "perfect unison" | "p1" => Interval::Unison,
+            "minor second" | "min2" | "m2" => Interval::MinorSecond,
+            "major second" | "maj2" => Interval::MajorSecond,
+            "minor third" | "min3" | "m3" => Interval::MinorThird,
+            "major third" | "maj3" => Interval::MajorThird,
+            "perfect fourth" | "p4" => Interval::PerfectFourth,
+            "tritone" | "augmented fourth" | "diminished fifth" | "tt" => Interval::Tritone,
+            "perfect fifth" | "p5" => Interval::PerfectFifth,
+            "minor sixth" | "min6" | "m6" => Interval::MinorSixth,
+            "major sixth" | "maj6" => Interval::MajorSixth,
+            "minor seventh" | "min7" | "m7" => Interval::MinorSeventh,
+            "major seventh" | "maj7" => Interval::MajorSeventh,
+            "octave" | "p8" => Interval::Octave,
+            _ => {
+                return Err(IntervalParseError(format!(
+                    "expected an interval name (unison, minor second, ... octave) or \
+                     0-12 semitones, got \"{s}\""
+                )))
+            }
+        };
+        Ok(interval)
+    }
+}
+
 /// panics if weights is empty or is longer than items
 fn choose_biased<'a, T>(rng: &mut impl Rng, items: &'a [T], weights: &[f32]) -> (usize, &'a T) {
     let weight_range = weights.iter().sum::<f32>();
@@ -89,6 +359,54 @@ fn choose_biased<'a, T>(rng: &mut impl Rng, items: &'a [T], weights: &[f32]) ->
     panic!();
 }
 
+/// Cooling factor applied to [`Difficulty::temperature`] on a correct
+/// guess; reheat amount applied on a miss.
+const COOLING_FACTOR: f32 = 0.9;
+const REHEAT_AMOUNT: f32 = 0.3;
+
+/// Simulated-annealing-style difficulty controller. `temperature` starts
+/// high (easy) and cools as the player's streak rises, reheating on a
+/// miss. Low temperature means faster playback, a wider octave span, and
+/// a shorter "choosing note" pause.
+#[derive(Debug, Clone, Copy)]
+struct Difficulty {
+    temperature: f32,
+}
+
+impl Difficulty {
+    fn new() -> Self {
+        Difficulty { temperature: 1.0 }
+    }
+
+    fn cool(&mut self) {
+        self.temperature *= COOLING_FACTOR;
+    }
+
+    fn reheat(&mut self) {
+        self.temperature = (self.temperature + REHEAT_AMOUNT).min(1.0);
+    }
+
+    /// Interpolates a duration between `hard` (at `temperature == 0.0`)
+    /// and `easy` (at `temperature == 1.0`), never going below `floor`.
+    fn lerp_duration(&self, hard: Duration, easy: Duration, floor: Duration) -> Duration {
+        let t = self.temperature.clamp(0.0, 1.0);
+        let secs = hard.as_secs_f32() + t * (easy.as_secs_f32() - hard.as_secs_f32());
+        Duration::from_secs_f32(secs).max(floor)
+    }
+
+    /// How many extra octaves above/below the home octave a played note
+    /// may be shifted into, widening as temperature drops.
+    fn octave_span(&self) -> i32 {
+        if self.temperature > 0.7 {
+            0
+        } else if self.temperature > 0.3 {
+            1
+        } else {
+            2
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 struct Stat {
     wins: u32,
@@ -126,102 +444,353 @@ impl Display for Stat {
     }
 }
 
-#[derive(Debug, Clone, Default)]
-struct Stats(Vec<Stat>);
+/// A quiz key (a [`Note`] or an [`Interval`]) that can be persisted as a
+/// single `i32` row in a stats file.
+trait StatKey: Copy {
+    fn stat_key(self) -> i32;
+}
+
+impl StatKey for Note {
+    fn stat_key(self) -> i32 {
+        self.0
+    }
+}
+
+impl StatKey for Interval {
+    fn stat_key(self) -> i32 {
+        self.semitones()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Stats<K> {
+    keys: Vec<K>,
+    stats: Vec<Stat>,
+    /// Column header for `keys`, e.g. `"note"` or `"interval"`.
+    label: &'static str,
+}
+
+/// File the per-note win/loss history is persisted to between sessions.
+const STATS_FILE: &str = "pitcher_stats.txt";
+
+/// File the interval-training mode's win/loss history is persisted to.
+const INTERVAL_STATS_FILE: &str = "pitcher_interval_stats.txt";
+
+/// Loads previously saved stats from `path`, keyed by [`StatKey::stat_key`].
+/// Returns an empty map if the file doesn't exist or can't be parsed.
+fn load_stats(path: &str) -> HashMap<i32, Stat> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+    contents
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let key = fields.next()?.parse().ok()?;
+            let wins = fields.next()?.parse().ok()?;
+            let losses = fields.next()?.parse().ok()?;
+            Some((key, Stat { wins, losses }))
+        })
+        .collect()
+}
+
+fn save_stats<K: StatKey>(path: &str, stats: &Stats<K>) {
+    let contents: String = stats
+        .keys
+        .iter()
+        .zip(&stats.stats)
+        .map(|(key, stat)| format!("{} {} {}\n", key.stat_key(), stat.wins, stat.losses))
+        .collect();
+    if let Err(e) = fs::write(path, contents) {
+        eprintln!("[Couldn't save stats to {path}: {e}]");
+    }
+}
+
+impl<K: StatKey> Stats<K> {
+    fn new(label: &'static str, keys: Vec<K>, saved: &HashMap<i32, Stat>) -> Self {
+        let stats = keys
+            .iter()
+            .map(|key| saved.get(&key.stat_key()).copied().unwrap_or_default())
+            .collect();
+        Stats { keys, stats, label }
+    }
 
-impl Stats {
     fn win(&mut self, index: usize) {
-        self.0[index].wins += 1;
+        self.stats[index].wins += 1;
     }
 
     fn lose(&mut self, index: usize) {
-        self.0[index].losses += 1;
+        self.stats[index].losses += 1;
     }
 
     fn weights(&self) -> Vec<f32> {
-        self.0.iter().map(|stat| stat.weight()).collect()
+        self.stats.iter().map(|stat| stat.weight()).collect()
     }
 }
 
-impl Display for Stats {
+impl<K: StatKey + Display> Stats<K> {
+    /// Renders the same win/loss/weight data as [`Display`] but as a
+    /// plain, single-header Markdown table for `--report` mode.
+    fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        out += &format!("| {} | win:loss | win% | pick weight |\n", self.label);
+        out += "|---|---|---|---|\n";
+        let mut total = Stat::default();
+        for (key, stat) in self.keys.iter().zip(&self.stats) {
+            total.wins += stat.wins;
+            total.losses += stat.losses;
+            out += &format!(
+                "| {key} | {}:{} | {:.0}% | {:.3} |\n",
+                stat.wins,
+                stat.losses,
+                stat.rate() * 100.0,
+                stat.weight(),
+            );
+        }
+        out += &format!(
+            "| **total** | {}:{} | {:.0}% | |\n",
+            total.wins,
+            total.losses,
+            total.rate() * 100.0,
+        );
+        out
+    }
+}
+
+impl<K: StatKey + Display> Display for Stats<K> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "|-------|-----------|----------|-------------|")?;
-        writeln!(f, "|  note |  win:loss |   win%   | pick weight |")?;
+        // Sized to fit the widest key label (e.g. "minor seventh" in
+        // interval mode is much wider than "F#4" in note mode).
+        let key_strs: Vec<String> = self.keys.iter().map(|key| key.to_string()).collect();
+        let width = key_strs
+            .iter()
+            .map(|s| s.len())
+            .chain([self.label.len(), "total".len()])
+            .max()
+            .unwrap_or(5);
+        let dashes = "-".repeat(width + 2);
+
+        writeln!(f, "|{dashes}|-----------|----------|-------------|")?;
+        writeln!(f, "| {:^width$} |  win:loss |   win%   | pick weight |", self.label)?;
         let mut total = Stat::default();
-        for (i, stat) in self.0.iter().enumerate() {
+        for (key_str, stat) in key_strs.iter().zip(&self.stats) {
             total.wins += stat.wins;
             total.losses += stat.losses;
-            writeln!(f, "|  {i:>2}   | {stat}   |    {:>1.3}    |", stat.weight())?;
+            writeln!(f, "| {key_str:^width$} | {stat}   |    {:>1.3}    |", stat.weight())?;
         }
-        writeln!(f, "|-------|-----------|----------|-------------|")?;
-        writeln!(f, "| total | {total}   |")?;
-        writeln!(f, "|-------|-----------|----------|")
+        writeln!(f, "|{dashes}|-----------|----------|-------------|")?;
+        writeln!(f, "| {:^width$} | {total}   |", "total")?;
+        writeln!(f, "|{dashes}|-----------|----------|")
     }
 }
 
-fn main() {
-    let (_stream, stream_handle) = OutputStream::try_default().unwrap();
-    let sink = Sink::try_new(&stream_handle).unwrap();
+fn play_win_jingle(sink: &Sink, fast_speed: Duration, normal_speed: Duration) {
+    play(sink, Note(0), fast_speed);
+    play(sink, Note(4), fast_speed);
+    play(sink, Note(12), normal_speed);
+    sleep(normal_speed);
+}
 
-    let mut notes = from_scale(0b_1111_1111_1111);
-    notes.push(12); // literally the only reason notes is mut
+fn play_lose_jingle(sink: &Sink, fast_speed: Duration, normal_speed: Duration) {
+    play(sink, Note(3), fast_speed);
+    play(sink, Note(2), fast_speed);
+    sleep(normal_speed);
+}
+
+/// Named-note ear training: play one note, guess its name.
+fn run_note_mode(rng: &mut impl Rng, sink: &Sink) {
+    let scale = input_try::<ScaleMask>(
+        "Pick a scale: major, natural_minor, harmonic_minor, major_pentatonic, blues, \
+         chromatic, or a custom 12-bit pattern like 101011010101. [Enter] for chromatic.",
+        "Scale> ",
+        "",
+    )
+    .unwrap_or(ScaleMask::CHROMATIC);
+
+    let mut notes = from_scale(scale.0);
+    notes.push(Note(12)); // literally the only reason notes is mut
 
-    let mut rng = thread_rng();
     let mut streak = 0;
     let normal_speed = Duration::from_secs_f32(0.2);
     let slow_speed = normal_speed * 2;
     let fast_speed = normal_speed / 2;
+    let speed_floor = fast_speed / 2;
 
-    let mut speed = normal_speed;
+    let mut difficulty = Difficulty::new();
 
-    let mut stats = Stats(vec![Stat::default(); notes.len()]);
+    let saved_stats = load_stats(STATS_FILE);
+    let mut stats = Stats::new("note", notes.clone(), &saved_stats);
 
-    play_scale(&sink, &notes, speed);
+    play_scale(sink, &notes, normal_speed);
     'game_loop: loop {
-        let (i, &note) = choose_biased(&mut rng, &notes, &stats.weights());
+        let (i, &note) = choose_biased(rng, &notes, &stats.weights());
+        let octave_shift = match difficulty.octave_span() {
+            0 => 0,
+            span => rng.gen_range(-span..=span),
+        };
+        let note = note + 12 * octave_shift;
+        let speed = difficulty.lerp_duration(fast_speed, normal_speed, speed_floor);
+        let reveal_pause = difficulty.lerp_duration(fast_speed, slow_speed, speed_floor);
+
         println!("Stats:\n{stats}");
+        println!("Difficulty: T = {:.2}", difficulty.temperature);
         println!("Choosing note...");
-        sleep(slow_speed);
-        play(&sink, note, speed);
+        sleep(reveal_pause);
+        play(sink, note, speed);
 
         'guess_loop: loop {
-            let Some(guess) = input_try::<i32>("Guess the note.", "> ", "?") else {
-                play(&sink, note, slow_speed);
+            let Some(guess) = input_try::<Note>("Guess the note.", "> ", "?") else {
+                play(sink, note, slow_speed);
                 continue 'guess_loop;
             };
 
             println!("You played: {guess}");
             sleep(fast_speed);
-            play(&sink, guess, speed);
+            play(sink, guess, speed);
             sleep(fast_speed);
             println!("Correct was:");
             sleep(fast_speed);
-            play(&sink, note, speed);
+            play(sink, note, speed);
             sleep(fast_speed);
 
             if guess == note {
                 stats.win(i);
+                save_stats(STATS_FILE, &stats);
                 streak = streak.max(0) + 1;
+                difficulty.cool();
 
                 println!("Correct! Streak: {streak}");
-                play(&sink, 0, fast_speed);
-                play(&sink, 4, fast_speed);
-                play(&sink, 12, normal_speed);
-                sleep(normal_speed);
+                play_win_jingle(sink, fast_speed, normal_speed);
+
+                println!();
+                break 'guess_loop;
+            } else {
+                stats.lose(i);
+                save_stats(STATS_FILE, &stats);
+                streak = streak.min(1) - 1;
+                difficulty.reheat();
+                println!("Incorrect :P Streak: {streak}");
+                play_lose_jingle(sink, fast_speed, normal_speed);
+                println!();
+            }
+        }
+    }
+}
+
+/// Interval-training ear training: play a random root then a second
+/// note, guess the interval between them.
+fn run_interval_mode(rng: &mut impl Rng, sink: &Sink) {
+    let intervals = Interval::ALL.to_vec();
+
+    let mut streak = 0;
+    let normal_speed = Duration::from_secs_f32(0.2);
+    let slow_speed = normal_speed * 2;
+    let fast_speed = normal_speed / 2;
+    let speed_floor = fast_speed / 2;
+
+    let mut difficulty = Difficulty::new();
+
+    let saved_stats = load_stats(INTERVAL_STATS_FILE);
+    let mut stats = Stats::new("interval", intervals.clone(), &saved_stats);
 
-                speed = normal_speed;
+    'game_loop: loop {
+        let (i, &interval) = choose_biased(rng, &intervals, &stats.weights());
+        let root = Note(rng.gen_range(0..12));
+        let second = root + interval.semitones();
+        let speed = difficulty.lerp_duration(fast_speed, normal_speed, speed_floor);
+        let reveal_pause = difficulty.lerp_duration(fast_speed, slow_speed, speed_floor);
+
+        println!("Stats:\n{stats}");
+        println!("Difficulty: T = {:.2}", difficulty.temperature);
+        println!("Choosing interval...");
+        sleep(reveal_pause);
+        append_note(sink, root, speed);
+        append_note(sink, second, speed);
+        sink.sleep_until_end();
+
+        'guess_loop: loop {
+            let Some(guess) = input_try::<Interval>("Guess the interval.", "> ", "?") else {
+                append_note(sink, root, slow_speed);
+                append_note(sink, second, slow_speed);
+                sink.sleep_until_end();
+                continue 'guess_loop;
+            };
+
+            println!("You said: {guess}");
+            sleep(fast_speed);
+            println!("It was: {interval}");
+            sleep(fast_speed);
+            append_note(sink, root, speed);
+            append_note(sink, second, speed);
+            sink.sleep_until_end();
+            sleep(fast_speed);
+
+            if guess == interval {
+                stats.win(i);
+                save_stats(INTERVAL_STATS_FILE, &stats);
+                streak = streak.max(0) + 1;
+                difficulty.cool();
+
+                println!("Correct! Streak: {streak}");
+                play_win_jingle(sink, fast_speed, normal_speed);
 
                 println!();
                 break 'guess_loop;
             } else {
                 stats.lose(i);
+                save_stats(INTERVAL_STATS_FILE, &stats);
                 streak = streak.min(1) - 1;
+                difficulty.reheat();
                 println!("Incorrect :P Streak: {streak}");
-                play(&sink, 3, fast_speed);
-                play(&sink, 2, fast_speed);
-                sleep(normal_speed);
+                play_lose_jingle(sink, fast_speed, normal_speed);
                 println!();
             }
         }
     }
 }
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let interval_mode = args.iter().any(|arg| arg == "--interval");
+
+    if args.iter().any(|arg| arg == "--report") {
+        if interval_mode {
+            let saved = load_stats(INTERVAL_STATS_FILE);
+            let mut intervals: Vec<Interval> = saved
+                .keys()
+                .copied()
+                .filter_map(Interval::from_semitones)
+                .collect();
+            intervals.sort();
+            let stats = Stats::new("interval", intervals, &saved);
+            print!("{}", stats.to_markdown());
+        } else {
+            let saved = load_stats(STATS_FILE);
+            let mut notes: Vec<Note> = saved.keys().copied().map(Note).collect();
+            notes.sort();
+            let stats = Stats::new("note", notes, &saved);
+            print!("{}", stats.to_markdown());
+        }
+        return;
+    }
+
+    let seed = args
+        .iter()
+        .position(|arg| arg == "--seed")
+        .and_then(|i| args.get(i + 1))
+        .map(|s| s.parse().expect("--seed expects a u64"));
+
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let (_stream, stream_handle) = OutputStream::try_default().unwrap();
+    let sink = Sink::try_new(&stream_handle).unwrap();
+
+    if interval_mode {
+        run_interval_mode(&mut rng, &sink);
+    } else {
+        run_note_mode(&mut rng, &sink);
+    }
+}